@@ -0,0 +1,261 @@
+//! `#[derive(BitRead, BitWrite)]` for bit-packed wire structs, recast for `bitbuf`
+//! from the binrw-style declarative approach: describe the field widths once,
+//! get zero-boilerplate round-tripping through any `BitBuf`/`BitBufMut`.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Expr, ExprLit, Fields, Ident, Lit, Meta, Variant};
+
+enum FieldKind {
+    Bits(u64),
+    Bool,
+    Bytes(u64),
+    Nested,
+}
+
+/// Reads `#[name = N]` where `N` is an integer literal, syn-2-style: attributes
+/// no longer parse via `Attribute::parse_meta`, so match `attr.meta` directly
+/// and pull the literal out of its `Expr::Lit`.
+fn meta_int(attr: &syn::Attribute) -> Option<(String, u64)> {
+    let Meta::NameValue(nv) = &attr.meta else {
+        return None;
+    };
+    let Expr::Lit(ExprLit {
+        lit: Lit::Int(int), ..
+    }) = &nv.value
+    else {
+        return None;
+    };
+    Some((nv.path.get_ident()?.to_string(), int.base10_parse().ok()?))
+}
+
+fn field_kind(attrs: &[syn::Attribute]) -> FieldKind {
+    for attr in attrs {
+        if let Meta::Path(path) = &attr.meta {
+            if path.is_ident("bool") {
+                return FieldKind::Bool;
+            }
+        }
+        if let Some((name, width)) = meta_int(attr) {
+            match name.as_str() {
+                "bits" => return FieldKind::Bits(width),
+                "bytes" => return FieldKind::Bytes(width),
+                _ => {}
+            }
+        }
+    }
+    FieldKind::Nested
+}
+
+fn discriminant_bits(attrs: &[syn::Attribute]) -> u64 {
+    for attr in attrs {
+        if let Some((name, width)) = meta_int(attr) {
+            if name == "bits" {
+                return width;
+            }
+        }
+    }
+    panic!("enums deriving BitRead/BitWrite need a top-level #[bits = N] discriminant width")
+}
+
+/// `#[bits = N]` is a runtime width fed straight into `write_uint`, which masks
+/// anything that doesn't fit -- so a discriminant too narrow for the variant
+/// count would otherwise alias distinct variants to the same tag on the wire
+/// with no error at all. Catch that at macro-expansion time instead.
+fn check_discriminant_capacity(tag_bits: u64, variant_count: usize) {
+    let capacity = 1u64.checked_shl(tag_bits as u32).unwrap_or(u64::MAX);
+    if variant_count as u64 > capacity {
+        panic!(
+            "enum has {variant_count} variants but #[bits = {tag_bits}] only has room for \
+             {capacity}; widen the discriminant width or remove variants"
+        );
+    }
+}
+
+fn read_field(kind: &FieldKind, ty: &syn::Type) -> TokenStream2 {
+    match kind {
+        FieldKind::Bits(bits) => quote! { (buf.read_uint(#bits as usize)? as #ty) },
+        FieldKind::Bool => quote! { buf.read_bool()? },
+        FieldKind::Bytes(len) => quote! {{
+            let mut bytes = [0u8; #len as usize];
+            buf.read_aligned_all(&mut bytes)?;
+            bytes
+        }},
+        FieldKind::Nested => quote! { <#ty as bitbuf::BitRead>::read(buf)? },
+    }
+}
+
+fn write_field(kind: &FieldKind, bits: &mut Vec<TokenStream2>, access: TokenStream2, ty: &syn::Type) {
+    let expr = match kind {
+        FieldKind::Bits(width) => quote! { buf.write_uint((#access) as u64, #width as usize)?; },
+        FieldKind::Bool => quote! { buf.write_bool(#access)?; },
+        FieldKind::Bytes(_) => quote! { buf.write_aligned_all(&(#access))?; },
+        FieldKind::Nested => {
+            let _ = ty;
+            quote! { bitbuf::BitWrite::write(&(#access), buf)?; }
+        }
+    };
+    bits.push(expr);
+}
+
+fn expand_read_fields(fields: &Fields) -> TokenStream2 {
+    match fields {
+        Fields::Named(named) => {
+            let inits = named.named.iter().map(|field| {
+                let name = field.ident.as_ref().unwrap();
+                let kind = field_kind(&field.attrs);
+                let expr = read_field(&kind, &field.ty);
+                quote! { #name: #expr }
+            });
+            quote! { { #(#inits),* } }
+        }
+        Fields::Unnamed(unnamed) => {
+            let inits = unnamed.unnamed.iter().map(|field| {
+                let kind = field_kind(&field.attrs);
+                read_field(&kind, &field.ty)
+            });
+            quote! { ( #(#inits),* ) }
+        }
+        Fields::Unit => quote! {},
+    }
+}
+
+fn expand_write_fields(fields: &Fields, self_prefix: Option<&Ident>) -> TokenStream2 {
+    let mut writes = Vec::new();
+    match fields {
+        Fields::Named(named) => {
+            for field in &named.named {
+                let name = field.ident.as_ref().unwrap();
+                let kind = field_kind(&field.attrs);
+                let access = match self_prefix {
+                    Some(_) => quote! { *#name },
+                    None => quote! { self.#name },
+                };
+                write_field(&kind, &mut writes, access, &field.ty);
+            }
+        }
+        Fields::Unnamed(unnamed) => {
+            for (i, field) in unnamed.unnamed.iter().enumerate() {
+                let kind = field_kind(&field.attrs);
+                let access = match self_prefix {
+                    Some(_) => {
+                        let binding = Ident::new(&format!("field{}", i), proc_macro2::Span::call_site());
+                        quote! { *#binding }
+                    }
+                    None => {
+                        let idx = syn::Index::from(i);
+                        quote! { self.#idx }
+                    }
+                };
+                write_field(&kind, &mut writes, access, &field.ty);
+            }
+        }
+        Fields::Unit => {}
+    }
+    quote! { #(#writes)* }
+}
+
+fn variant_pattern_bindings(fields: &Fields) -> TokenStream2 {
+    match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|f| f.ident.as_ref().unwrap());
+            quote! { { #(#names),* } }
+        }
+        Fields::Unnamed(unnamed) => {
+            let names = (0..unnamed.unnamed.len())
+                .map(|i| Ident::new(&format!("field{}", i), proc_macro2::Span::call_site()));
+            quote! { ( #(#names),* ) }
+        }
+        Fields::Unit => quote! {},
+    }
+}
+
+#[proc_macro_derive(BitRead, attributes(bits, bool, bytes))]
+pub fn derive_bit_read(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let fields = expand_read_fields(&data.fields);
+            quote! { Ok(#name #fields) }
+        }
+        Data::Enum(data) => {
+            let tag_bits = discriminant_bits(&input.attrs);
+            check_discriminant_capacity(tag_bits, data.variants.len());
+            let arms = data.variants.iter().enumerate().map(|(i, variant)| {
+                let vname = &variant.ident;
+                let fields = expand_read_fields(&variant.fields);
+                let idx = i as u64;
+                quote! { #idx => Ok(#name::#vname #fields) }
+            });
+            quote! {
+                let tag = buf.read_uint(#tag_bits as usize)?;
+                match tag {
+                    #(#arms,)*
+                    _ => Err(bitbuf::BitCodecError::InvalidDiscriminant),
+                }
+            }
+        }
+        Data::Union(_) => panic!("BitRead cannot be derived for unions"),
+    };
+
+    let expanded = quote! {
+        impl bitbuf::BitRead for #name {
+            fn read<B: bitbuf::BitBuf>(buf: &mut B) -> Result<Self, bitbuf::BitCodecError> {
+                #body
+            }
+        }
+    };
+    expanded.into()
+}
+
+#[proc_macro_derive(BitWrite, attributes(bits, bool, bytes))]
+pub fn derive_bit_write(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let body = match &input.data {
+        Data::Struct(data) => expand_write_fields(&data.fields, None),
+        Data::Enum(data) => {
+            let tag_bits = discriminant_bits(&input.attrs);
+            check_discriminant_capacity(tag_bits, data.variants.len());
+            let arms: Vec<TokenStream2> = data
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant): (usize, &Variant)| {
+                    let vname = &variant.ident;
+                    let idx = i as u64;
+                    let bindings = variant_pattern_bindings(&variant.fields);
+                    let writes = expand_write_fields(&variant.fields, Some(vname));
+                    quote! {
+                        #name::#vname #bindings => {
+                            buf.write_uint(#idx, #tag_bits as usize)?;
+                            #writes
+                        }
+                    }
+                })
+                .collect();
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => panic!("BitWrite cannot be derived for unions"),
+    };
+
+    let expanded = quote! {
+        impl bitbuf::BitWrite for #name {
+            fn write<B: bitbuf::BitBufMut>(&self, buf: &mut B) -> Result<(), bitbuf::Insufficient> {
+                #body
+                Ok(())
+            }
+        }
+    };
+    expanded.into()
+}
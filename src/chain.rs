@@ -0,0 +1,247 @@
+use crate::{BitBuf, BitBufMut, BitSlice, BitSliceMut, Insufficient, Overflow};
+
+/// Two [`BitBuf`]s chained together as one contiguous bit stream, `A` first.
+pub struct Chain<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> Chain<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Chain { a, b }
+    }
+
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+
+    pub fn first_ref(&self) -> &A {
+        &self.a
+    }
+
+    pub fn first_mut(&mut self) -> &mut A {
+        &mut self.a
+    }
+
+    pub fn last_ref(&self) -> &B {
+        &self.b
+    }
+
+    pub fn last_mut(&mut self) -> &mut B {
+        &mut self.b
+    }
+}
+
+impl<A: BitBuf, B: BitBuf> BitBuf for Chain<A, B> {
+    fn advance(&mut self, bits: usize) -> Result<(), Insufficient> {
+        if bits > self.remaining() {
+            return Err(Insufficient);
+        }
+        let a_rem = self.a.remaining();
+        let from_a = if bits > a_rem { a_rem } else { bits };
+        if from_a > 0 {
+            self.a.advance(from_a).unwrap();
+        }
+        let from_b = bits - from_a;
+        if from_b > 0 {
+            self.b.advance(from_b).unwrap();
+        }
+        Ok(())
+    }
+
+    fn read(&mut self, dst: &mut [u8], bits: usize) -> Result<usize, Overflow> {
+        let re = self.remaining();
+        let bits = if bits > re { re } else { bits };
+        if dst.len() * 8 < bits {
+            return Err(Overflow);
+        }
+        let mut target = BitSliceMut::new(dst);
+        let mut remaining = bits;
+        while remaining >= 8 {
+            let byte = self.read_byte().unwrap();
+            target.write_byte(byte).unwrap();
+            remaining -= 8;
+        }
+        while remaining > 0 {
+            let bit = self.read_bool().unwrap();
+            target.write_bool(bit).unwrap();
+            remaining -= 1;
+        }
+        Ok(bits)
+    }
+
+    fn read_bool(&mut self) -> Result<bool, Insufficient> {
+        if self.a.remaining() > 0 {
+            self.a.read_bool()
+        } else {
+            self.b.read_bool()
+        }
+    }
+
+    fn read_byte(&mut self) -> Result<u8, Insufficient> {
+        let a_rem = self.a.remaining();
+        if a_rem >= 8 {
+            self.a.read_byte()
+        } else if a_rem == 0 {
+            self.b.read_byte()
+        } else {
+            if self.remaining() < 8 {
+                return Err(Insufficient);
+            }
+            let lo_bits = 8 - a_rem;
+            let hi = self.a.read_uint(a_rem)? as u8;
+            let lo = self.b.read_uint(lo_bits)? as u8;
+            Ok((hi << lo_bits) | lo)
+        }
+    }
+
+    fn remaining(&self) -> usize {
+        self.a.remaining() + self.b.remaining()
+    }
+
+    fn len(&self) -> usize {
+        self.a.len() + self.b.len()
+    }
+}
+
+/// Two [`BitBufMut`]s chained together as one contiguous writable bit stream, `A` first.
+pub struct ChainMut<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> ChainMut<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        ChainMut { a, b }
+    }
+
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+
+    pub fn first_ref(&self) -> &A {
+        &self.a
+    }
+
+    pub fn first_mut(&mut self) -> &mut A {
+        &mut self.a
+    }
+
+    pub fn last_ref(&self) -> &B {
+        &self.b
+    }
+
+    pub fn last_mut(&mut self) -> &mut B {
+        &mut self.b
+    }
+}
+
+impl<A: BitBufMut, B: BitBufMut> BitBufMut for ChainMut<A, B> {
+    fn len(&self) -> usize {
+        self.a.len() + self.b.len()
+    }
+
+    fn remaining(&self) -> usize {
+        self.a.remaining() + self.b.remaining()
+    }
+
+    fn advance(&mut self, bits: usize) -> Result<(), Insufficient> {
+        if bits > self.remaining() {
+            return Err(Insufficient);
+        }
+        let a_rem = self.a.remaining();
+        let from_a = if bits > a_rem { a_rem } else { bits };
+        if from_a > 0 {
+            self.a.advance(from_a).unwrap();
+        }
+        let from_b = bits - from_a;
+        if from_b > 0 {
+            self.b.advance(from_b).unwrap();
+        }
+        Ok(())
+    }
+
+    fn write_bool(&mut self, item: bool) -> Result<(), Insufficient> {
+        if self.a.remaining() > 0 {
+            self.a.write_bool(item)
+        } else {
+            self.b.write_bool(item)
+        }
+    }
+
+    fn write_byte(&mut self, item: u8) -> Result<(), Insufficient> {
+        let a_rem = self.a.remaining();
+        if a_rem >= 8 {
+            self.a.write_byte(item)
+        } else if a_rem == 0 {
+            self.b.write_byte(item)
+        } else {
+            if self.remaining() < 8 {
+                return Err(Insufficient);
+            }
+            let lo_bits = 8 - a_rem;
+            self.a.write_uint((item >> lo_bits) as u64, a_rem)?;
+            self.b
+                .write_uint((item & (0xffu8 >> a_rem)) as u64, lo_bits)?;
+            Ok(())
+        }
+    }
+
+    fn write(&mut self, data: &[u8], bits: usize) -> Result<usize, Overflow> {
+        let re = self.remaining();
+        let bits = if bits > re { re } else { bits };
+        if data.len() * 8 < bits {
+            return Err(Overflow);
+        }
+        let mut source = BitSlice::new(data);
+        let mut remaining = bits;
+        while remaining >= 8 {
+            let byte = source.read_byte().unwrap();
+            self.write_byte(byte).unwrap();
+            remaining -= 8;
+        }
+        while remaining > 0 {
+            let bit = source.read_bool().unwrap();
+            self.write_bool(bit).unwrap();
+            remaining -= 1;
+        }
+        Ok(bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_byte_straddles_the_chain_boundary() {
+        // the 5 leading bits of a are thrown away; its last 3 bits (0b101)
+        // become the high bits of the read byte, with b's first 5 (0b10110)
+        // supplying the rest.
+        let a_data = [0b0000_0101u8];
+        let b_data = [0b1011_0000u8];
+        let a = BitSlice::new(&a_data);
+        let b = BitSlice::new(&b_data);
+        let mut chain = a.chain(b);
+        chain.advance(5).unwrap(); // leave exactly 3 bits of `a`
+
+        let byte = chain.read_byte().unwrap();
+        assert_eq!(byte, 0b1011_0110);
+        assert_eq!(chain.len(), 5 + 8);
+    }
+
+    #[test]
+    fn write_byte_straddles_the_chain_boundary() {
+        let mut a_data = [0u8];
+        let mut b_data = [0u8];
+        let mut a = BitSliceMut::new(&mut a_data);
+        a.advance(5).unwrap(); // leave exactly 3 bits of `a`
+        let b = BitSliceMut::new(&mut b_data);
+        let mut chain = a.chain(b);
+
+        chain.write_byte(0b1011_0110).unwrap();
+
+        assert_eq!(a_data[0], 0b0000_0101);
+        assert_eq!(b_data[0], 0b1011_0000);
+    }
+}
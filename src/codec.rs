@@ -0,0 +1,44 @@
+use crate::{BitBuf, BitBufMut, Insufficient};
+
+/// Why a [`BitRead::read`] call failed.
+///
+/// Distinct from [`Insufficient`] on purpose: running out of bits is a
+/// transient condition a caller can retry once more data arrives, while an
+/// [`InvalidDiscriminant`](BitCodecError::InvalidDiscriminant) means the bits
+/// were all there but didn't decode to anything meaningful (e.g. a
+/// `#[derive(BitRead)]`ed enum reading a tag value with no matching variant).
+/// Conflating the two would make a retry-on-`Insufficient` caller spin
+/// forever on a complete-but-malformed stream.
+#[derive(Debug)]
+pub enum BitCodecError {
+    Insufficient(Insufficient),
+    InvalidDiscriminant,
+}
+
+impl From<Insufficient> for BitCodecError {
+    fn from(insufficient: Insufficient) -> Self {
+        BitCodecError::Insufficient(insufficient)
+    }
+}
+
+/// Reads `Self` bit-by-bit from a [`BitBuf`], top-to-bottom field order.
+///
+/// Implemented by hand or via `#[derive(BitRead)]` from the `bitbuf-derive` crate.
+pub trait BitRead: Sized {
+    fn read<B: BitBuf>(buf: &mut B) -> Result<Self, BitCodecError>;
+}
+
+/// Writes `Self` bit-by-bit into a [`BitBufMut`], top-to-bottom field order.
+///
+/// Implemented by hand or via `#[derive(BitWrite)]` from the `bitbuf-derive` crate.
+pub trait BitWrite {
+    fn write<B: BitBufMut>(&self, buf: &mut B) -> Result<(), Insufficient>;
+}
+
+/// A type that can both be read from and written to a bit stream.
+///
+/// Blanket-implemented for anything that derives (or hand-implements) both
+/// [`BitRead`] and [`BitWrite`]; there is nothing to implement directly.
+pub trait BitCodec: BitRead + BitWrite {}
+
+impl<T: BitRead + BitWrite> BitCodec for T {}
@@ -0,0 +1,134 @@
+use crate::{BitBuf, BitSlice, Insufficient, Overflow};
+
+/// A [`BitBuf`] over a fixed slice that can be rewound: unlike [`BitSlice`], whose
+/// `advance` consumes its backing slice in place, a `BitCursor` keeps the original
+/// base slice and total length around and tracks only a bit `position`, so it can
+/// be marked, reset, or seeked backward without losing access to bits already read.
+#[derive(Debug)]
+pub struct BitCursor<'a> {
+    base: &'a [u8],
+    position: usize,
+    mark: usize,
+}
+
+impl<'a> BitCursor<'a> {
+    pub fn new(base: &'a [u8]) -> Self {
+        BitCursor {
+            base,
+            position: 0,
+            mark: 0,
+        }
+    }
+
+    /// Total number of bits in the backing slice.
+    pub fn total_bits(&self) -> usize {
+        self.base.len() * 8
+    }
+
+    /// Number of bits consumed so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Remembers the current position for a later [`Self::reset_to_mark`].
+    pub fn mark(&mut self) {
+        self.mark = self.position;
+    }
+
+    /// Rewinds to the position last recorded with [`Self::mark`].
+    pub fn reset_to_mark(&mut self) {
+        self.position = self.mark;
+    }
+
+    /// Repositions to an absolute bit offset from the start of the slice.
+    pub fn seek(&mut self, bit_offset: usize) -> Result<(), Insufficient> {
+        if bit_offset > self.total_bits() {
+            return Err(Insufficient);
+        }
+        self.position = bit_offset;
+        Ok(())
+    }
+
+    fn window(&self) -> BitSlice<'a> {
+        let byte_offset = self.position / 8;
+        let mut window = BitSlice::new(&self.base[byte_offset..]);
+        window.advance(self.position & 7).unwrap();
+        window
+    }
+
+    /// Reads the next bit without advancing the cursor.
+    pub fn peek_bool(&self) -> Result<bool, Insufficient> {
+        self.window().read_bool()
+    }
+
+    /// Reads the next byte without advancing the cursor.
+    pub fn peek_byte(&self) -> Result<u8, Insufficient> {
+        self.window().read_byte()
+    }
+}
+
+impl<'a> BitBuf for BitCursor<'a> {
+    fn advance(&mut self, bits: usize) -> Result<(), Insufficient> {
+        if bits > self.remaining() {
+            return Err(Insufficient);
+        }
+        self.position += bits;
+        Ok(())
+    }
+
+    fn read(&mut self, dst: &mut [u8], bits: usize) -> Result<usize, Overflow> {
+        let mut window = self.window();
+        let read = window.read(dst, bits)?;
+        self.position += read;
+        Ok(read)
+    }
+
+    fn read_bool(&mut self) -> Result<bool, Insufficient> {
+        let bit = self.peek_bool()?;
+        self.position += 1;
+        Ok(bit)
+    }
+
+    fn read_byte(&mut self) -> Result<u8, Insufficient> {
+        let byte = self.peek_byte()?;
+        self.position += 8;
+        Ok(byte)
+    }
+
+    fn remaining(&self) -> usize {
+        self.total_bits() - self.position
+    }
+
+    fn len(&self) -> usize {
+        self.position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mark_reset_and_seek_rewind_the_cursor() {
+        let data = [0xA5u8, 0x3C];
+        let mut cursor = BitCursor::new(&data);
+
+        let first = cursor.read_byte().unwrap();
+        assert_eq!(first, 0xA5);
+
+        cursor.mark();
+        let second = cursor.read_byte().unwrap();
+        assert_eq!(second, 0x3C);
+        assert_eq!(cursor.position(), 16);
+
+        cursor.reset_to_mark();
+        assert_eq!(cursor.position(), 8);
+        assert_eq!(cursor.read_byte().unwrap(), 0x3C);
+
+        cursor.seek(0).unwrap();
+        assert_eq!(cursor.position(), 0);
+        assert_eq!(cursor.read_byte().unwrap(), 0xA5);
+
+        assert!(cursor.seek(17).is_err());
+    }
+}
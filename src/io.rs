@@ -0,0 +1,95 @@
+use std::io;
+
+use crate::{BitBuf, BitBufMut};
+
+/// Adapts a [`BitBuf`] into [`std::io::Read`], yielding whole bytes.
+pub struct Reader<B> {
+    buf: B,
+}
+
+impl<B: BitBuf> Reader<B> {
+    pub fn new(buf: B) -> Self {
+        Reader { buf }
+    }
+
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+
+    pub fn get_ref(&self) -> &B {
+        &self.buf
+    }
+
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.buf
+    }
+}
+
+impl<B: BitBuf> io::Read for Reader<B> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Ok(self.buf.read_aligned(buf))
+    }
+}
+
+/// Adapts a [`BitBufMut`] into [`std::io::Write`], accepting whole bytes.
+pub struct Writer<B> {
+    buf: B,
+}
+
+impl<B: BitBufMut> Writer<B> {
+    pub fn new(buf: B) -> Self {
+        Writer { buf }
+    }
+
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+
+    pub fn get_ref(&self) -> &B {
+        &self.buf
+    }
+
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.buf
+    }
+}
+
+impl<B: BitBufMut> io::Write for Writer<B> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(self.buf.write_aligned(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BitSlice;
+    use std::io::{Read, Write};
+
+    #[test]
+    fn reader_returns_a_short_count_when_fewer_than_a_full_buffer_of_bytes_remain() {
+        let data = [0x11u8, 0x22, 0x33];
+        let mut reader = Reader::new(BitSlice::new(&data));
+
+        let mut out = [0u8; 4];
+        let n = reader.read(&mut out).unwrap();
+
+        assert_eq!(n, 3);
+        assert_eq!(&out[..3], &data);
+    }
+
+    #[test]
+    fn writer_returns_a_short_count_when_the_buffer_cant_hold_everything() {
+        let mut data = [0u8; 2];
+        let mut writer = Writer::new(crate::BitSliceMut::new(&mut data));
+
+        let n = writer.write(&[0xAA, 0xBB, 0xCC]).unwrap();
+
+        assert_eq!(n, 2);
+        assert_eq!(data, [0xAA, 0xBB]);
+    }
+}
@@ -1,10 +1,32 @@
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::{
     borrow::{Borrow, BorrowMut},
     mem::replace,
 };
 
+mod chain;
+mod codec;
+mod cursor;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "alloc")]
+mod vec;
+
+pub use chain::{Chain, ChainMut};
+pub use codec::{BitCodec, BitCodecError, BitRead, BitWrite};
+pub use cursor::BitCursor;
+#[cfg(feature = "std")]
+pub use io::{Reader, Writer};
+#[cfg(feature = "alloc")]
+pub use vec::BitVec;
+
 #[derive(Debug)]
 pub struct Insufficient;
 
@@ -211,6 +233,68 @@ impl<T: Borrow<[u8]>> CappedDrain<T> {
     }
 }
 
+macro_rules! read_int_methods {
+    ($ty:ty, $bytes:literal, $be:ident, $le:ident, $ne:ident) => {
+        fn $be(&mut self) -> Result<$ty, Insufficient> {
+            let mut buf = [0u8; $bytes];
+            self.read_aligned_all(&mut buf)?;
+            Ok(<$ty>::from_be_bytes(buf))
+        }
+        fn $le(&mut self) -> Result<$ty, Insufficient> {
+            let mut buf = [0u8; $bytes];
+            self.read_aligned_all(&mut buf)?;
+            Ok(<$ty>::from_le_bytes(buf))
+        }
+        fn $ne(&mut self) -> Result<$ty, Insufficient> {
+            let mut buf = [0u8; $bytes];
+            self.read_aligned_all(&mut buf)?;
+            Ok(<$ty>::from_ne_bytes(buf))
+        }
+    };
+}
+
+macro_rules! write_int_methods {
+    ($ty:ty, $be:ident, $le:ident, $ne:ident) => {
+        fn $be(&mut self, value: $ty) -> Result<(), Insufficient> {
+            self.write_aligned_all(&value.to_be_bytes())
+        }
+        fn $le(&mut self, value: $ty) -> Result<(), Insufficient> {
+            self.write_aligned_all(&value.to_le_bytes())
+        }
+        fn $ne(&mut self, value: $ty) -> Result<(), Insufficient> {
+            self.write_aligned_all(&value.to_ne_bytes())
+        }
+    };
+}
+
+macro_rules! forward_read_int_methods {
+    ($ty:ty, $be:ident, $le:ident, $ne:ident) => {
+        fn $be(&mut self) -> Result<$ty, Insufficient> {
+            T::$be(self)
+        }
+        fn $le(&mut self) -> Result<$ty, Insufficient> {
+            T::$le(self)
+        }
+        fn $ne(&mut self) -> Result<$ty, Insufficient> {
+            T::$ne(self)
+        }
+    };
+}
+
+macro_rules! forward_write_int_methods {
+    ($ty:ty, $be:ident, $le:ident, $ne:ident) => {
+        fn $be(&mut self, value: $ty) -> Result<(), Insufficient> {
+            T::$be(self, value)
+        }
+        fn $le(&mut self, value: $ty) -> Result<(), Insufficient> {
+            T::$le(self, value)
+        }
+        fn $ne(&mut self, value: $ty) -> Result<(), Insufficient> {
+            T::$ne(self, value)
+        }
+    };
+}
+
 pub trait BitBuf {
     fn advance(&mut self, bits: usize) -> Result<(), Insufficient>;
     fn read_all(&mut self, dst: &mut [u8], bits: usize) -> Result<(), UnalignedError> {
@@ -224,8 +308,10 @@ pub trait BitBuf {
     }
     fn read(&mut self, dst: &mut [u8], bits: usize) -> Result<usize, Overflow>;
     fn read_aligned(&mut self, dst: &mut [u8]) -> usize {
-        self.read(dst, dst.len() * 8)
-            .expect("overflowed aligned slice")
+        let bytes = dst.len().min(self.remaining() / 8);
+        self.read(&mut dst[..bytes], bytes * 8)
+            .expect("overflowed aligned slice");
+        bytes
     }
     fn read_aligned_all(&mut self, dst: &mut [u8]) -> Result<(), Insufficient> {
         self.read_all(dst, dst.len() * 8).map_err(|e| match e {
@@ -239,11 +325,42 @@ pub trait BitBuf {
         self.read_aligned_all(&mut data)?;
         Ok(data[0])
     }
+    fn read_uint(&mut self, bits: usize) -> Result<u64, Insufficient> {
+        if !(1..=64).contains(&bits) || self.remaining() < bits {
+            return Err(Insufficient);
+        }
+        let mut acc = 0u64;
+        let mut remaining = bits;
+        while remaining >= 8 {
+            acc = (acc << 8) | self.read_byte()? as u64;
+            remaining -= 8;
+        }
+        while remaining > 0 {
+            acc = (acc << 1) | self.read_bool()? as u64;
+            remaining -= 1;
+        }
+        Ok(acc)
+    }
+    read_int_methods!(u16, 2, read_u16_be, read_u16_le, read_u16_ne);
+    read_int_methods!(u32, 4, read_u32_be, read_u32_le, read_u32_ne);
+    read_int_methods!(u64, 8, read_u64_be, read_u64_le, read_u64_ne);
+    read_int_methods!(i16, 2, read_i16_be, read_i16_le, read_i16_ne);
+    read_int_methods!(i32, 4, read_i32_be, read_i32_le, read_i32_ne);
+    read_int_methods!(i64, 8, read_i64_be, read_i64_le, read_i64_ne);
+    fn chain<B: BitBuf>(self, other: B) -> Chain<Self, B>
+    where
+        Self: Sized,
+    {
+        Chain::new(self, other)
+    }
     fn remaining(&self) -> usize;
     fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
-impl<'a, T: ?Sized + BitBuf> BitBuf for &'a mut T {
+impl<T: ?Sized + BitBuf> BitBuf for &mut T {
     fn advance(&mut self, bits: usize) -> Result<(), Insufficient> {
         T::advance(self, bits)
     }
@@ -265,6 +382,15 @@ impl<'a, T: ?Sized + BitBuf> BitBuf for &'a mut T {
     fn read_byte(&mut self) -> Result<u8, Insufficient> {
         T::read_byte(self)
     }
+    fn read_uint(&mut self, bits: usize) -> Result<u64, Insufficient> {
+        T::read_uint(self, bits)
+    }
+    forward_read_int_methods!(u16, read_u16_be, read_u16_le, read_u16_ne);
+    forward_read_int_methods!(u32, read_u32_be, read_u32_le, read_u32_ne);
+    forward_read_int_methods!(u64, read_u64_be, read_u64_le, read_u64_ne);
+    forward_read_int_methods!(i16, read_i16_be, read_i16_le, read_i16_ne);
+    forward_read_int_methods!(i32, read_i32_be, read_i32_le, read_i32_ne);
+    forward_read_int_methods!(i64, read_i64_be, read_i64_le, read_i64_ne);
     fn remaining(&self) -> usize {
         T::remaining(self)
     }
@@ -273,7 +399,7 @@ impl<'a, T: ?Sized + BitBuf> BitBuf for &'a mut T {
     }
 }
 
-impl<'a, T: ?Sized + BitBufMut> BitBufMut for &'a mut T {
+impl<T: ?Sized + BitBufMut> BitBufMut for &mut T {
     fn advance(&mut self, bits: usize) -> Result<(), Insufficient> {
         T::advance(self, bits)
     }
@@ -295,6 +421,15 @@ impl<'a, T: ?Sized + BitBufMut> BitBufMut for &'a mut T {
     fn write_byte(&mut self, byte: u8) -> Result<(), Insufficient> {
         T::write_byte(self, byte)
     }
+    fn write_uint(&mut self, value: u64, bits: usize) -> Result<(), Insufficient> {
+        T::write_uint(self, value, bits)
+    }
+    forward_write_int_methods!(u16, write_u16_be, write_u16_le, write_u16_ne);
+    forward_write_int_methods!(u32, write_u32_be, write_u32_le, write_u32_ne);
+    forward_write_int_methods!(u64, write_u64_be, write_u64_le, write_u64_ne);
+    forward_write_int_methods!(i16, write_i16_be, write_i16_le, write_i16_ne);
+    forward_write_int_methods!(i32, write_i32_be, write_i32_le, write_i32_ne);
+    forward_write_int_methods!(i64, write_i64_be, write_i64_le, write_i64_ne);
     fn remaining(&self) -> usize {
         T::remaining(self)
     }
@@ -327,15 +462,14 @@ impl<'a> BitBuf for BitSlice<'a> {
     }
 
     fn read_aligned(&mut self, dst: &mut [u8]) -> usize {
-        let re = self.remaining();
-        let len = dst.len();
-        let len = if len * 8 > re { re } else { len };
-        if len & 7 != 0 {
-            return self.read(dst, len * 8).expect("overflowed aligned slice");
-        } else {
-            for i in 0..dst.len() {
-                dst[i] = self.byte_at_offset(i * 8).unwrap();
+        let len = dst.len().min(self.remaining() / 8);
+        if self.prefix == 0 {
+            for (i, byte) in dst[..len].iter_mut().enumerate() {
+                *byte = self.byte_at_offset(i * 8).unwrap();
             }
+            self.advance(len * 8).unwrap();
+        } else {
+            self.read(dst, len * 8).expect("overflowed aligned slice");
         }
         len
     }
@@ -352,8 +486,8 @@ impl<'a> BitBuf for BitSlice<'a> {
         if len * 8 < bits {
             return Err(Overflow);
         }
-        for i in 0..bytes {
-            dst[i] = self
+        for (i, byte) in dst[..bytes].iter_mut().enumerate() {
+            *byte = self
                 .byte_at_offset(i * 8)
                 .map_err(UnalignedError::Insufficient)
                 .unwrap();
@@ -419,7 +553,7 @@ impl<'a> BitSlice<'a> {
             } else {
                 let offset_rem_inv = 8 - offset_rem;
                 Ok(if size + offset_rem <= 8 {
-                    ((self.data[offset_bytes] & (255 >> offset_rem)) << offset_rem)
+                    (self.data[offset_bytes] & (255 >> offset_rem)) << offset_rem
                 } else {
                     ((self.data[offset_bytes] & (255 >> offset_rem)) << offset_rem)
                         + ((self.data[(offset_bytes) + 1] & (255 << offset_rem_inv))
@@ -443,6 +577,9 @@ pub struct BitSliceMut<'a> {
 
 pub trait BitBufMut {
     fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
     fn remaining(&self) -> usize;
     fn advance(&mut self, bits: usize) -> Result<(), Insufficient>;
     fn write_bool(&mut self, item: bool) -> Result<(), Insufficient>;
@@ -460,8 +597,10 @@ pub trait BitBufMut {
         self.write_aligned_all(&[data])
     }
     fn write_aligned(&mut self, data: &[u8]) -> usize {
-        self.write(data, data.len() * 8)
-            .expect("overflowed aligned buffer")
+        let bytes = data.len().min(self.remaining() / 8);
+        self.write(&data[..bytes], bytes * 8)
+            .expect("overflowed aligned buffer");
+        bytes
     }
     fn write_aligned_all(&mut self, data: &[u8]) -> Result<(), Insufficient> {
         let bits = data.len() * 8;
@@ -472,6 +611,38 @@ pub trait BitBufMut {
             Ok(())
         }
     }
+    fn write_uint(&mut self, value: u64, bits: usize) -> Result<(), Insufficient> {
+        if !(1..=64).contains(&bits) || bits > self.remaining() {
+            return Err(Insufficient);
+        }
+        let value = if bits == 64 {
+            value
+        } else {
+            value & ((1u64 << bits) - 1)
+        };
+        let mut remaining = bits;
+        while remaining >= 8 {
+            remaining -= 8;
+            self.write_byte((value >> remaining) as u8)?;
+        }
+        while remaining > 0 {
+            remaining -= 1;
+            self.write_bool((value >> remaining) & 1 != 0)?;
+        }
+        Ok(())
+    }
+    write_int_methods!(u16, write_u16_be, write_u16_le, write_u16_ne);
+    write_int_methods!(u32, write_u32_be, write_u32_le, write_u32_ne);
+    write_int_methods!(u64, write_u64_be, write_u64_le, write_u64_ne);
+    write_int_methods!(i16, write_i16_be, write_i16_le, write_i16_ne);
+    write_int_methods!(i32, write_i32_be, write_i32_le, write_i32_ne);
+    write_int_methods!(i64, write_i64_be, write_i64_le, write_i64_ne);
+    fn chain<B: BitBufMut>(self, other: B) -> ChainMut<Self, B>
+    where
+        Self: Sized,
+    {
+        ChainMut::new(self, other)
+    }
 }
 
 impl<'a> BitBufMut for BitSliceMut<'a> {
@@ -503,7 +674,7 @@ impl<'a> BitBufMut for BitSliceMut<'a> {
     }
 
     fn write_bool(&mut self, item: bool) -> Result<(), Insufficient> {
-        if self.data.len() == 0 {
+        if self.data.is_empty() {
             return Err(Insufficient);
         }
         let byte = &mut self.data[0];
@@ -517,14 +688,14 @@ impl<'a> BitBufMut for BitSliceMut<'a> {
     }
 
     fn write_byte(&mut self, item: u8) -> Result<(), Insufficient> {
-        if self.data.len() == 0 {
-            return Err(Insufficient.into());
+        if self.data.is_empty() {
+            return Err(Insufficient);
         }
         if self.prefix == 0 {
             self.data[0] = item;
         } else {
             if self.data.len() == 1 {
-                return Err(Insufficient.into());
+                return Err(Insufficient);
             }
             let inv_prefix = 8 - self.prefix;
             self.data[0] |= item >> self.prefix;
@@ -550,9 +721,8 @@ impl<'a> BitBufMut for BitSliceMut<'a> {
         if len * 8 < bits {
             return Err(Overflow);
         }
-        for i in 0..bytes {
-            self.write_byte(data[i])
-                .expect("overflowed restricted buffer");
+        for &byte in &data[..bytes] {
+            self.write_byte(byte).expect("overflowed restricted buffer");
         }
         let rem = bits & 7;
         if rem != 0 {
@@ -566,13 +736,11 @@ impl<'a> BitBufMut for BitSliceMut<'a> {
     }
 
     fn write_aligned(&mut self, data: &[u8]) -> usize {
-        let len = data.len() * 8;
-        let re = self.remaining();
-        let len = if len > re { re } else { len };
-        if len & 7 != 0 {
-            BitBufMut::write(self, data, len).expect("overflowed aligned buffer");
+        let len = data.len().min(self.remaining() / 8);
+        if self.prefix != 0 {
+            BitBufMut::write(self, data, len * 8).expect("overflowed aligned buffer");
         } else {
-            for byte in &data[..len / 8] {
+            for byte in &data[..len] {
                 self.write_byte(*byte).expect("overflowed aligned buffer");
             }
         }
@@ -610,3 +778,52 @@ impl<'a> BitSliceMut<'a> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uint_round_trips_byte_straddling_widths() {
+        for bits in [1usize, 7, 8, 12, 20, 33, 63, 64] {
+            let value = u64::MAX >> (64 - bits);
+            let mut data = [0u8; 9];
+            BitSliceMut::new(&mut data).write_uint(value, bits).unwrap();
+            let read_back = BitSlice::new(&data).read_uint(bits).unwrap();
+            assert_eq!(read_back, value, "round trip failed for {bits} bits");
+        }
+    }
+
+    #[test]
+    fn write_uint_masks_value_to_the_requested_width() {
+        let mut data = [0u8; 2];
+        BitSliceMut::new(&mut data).write_uint(0xFFFF, 12).unwrap();
+        let read_back = BitSlice::new(&data).read_uint(12).unwrap();
+        assert_eq!(read_back, 0xFFF);
+    }
+
+    #[test]
+    fn uint_rejects_bit_widths_outside_one_to_sixty_four() {
+        let mut data = [0u8; 9];
+        assert!(BitSliceMut::new(&mut data).write_uint(0, 0).is_err());
+        assert!(BitSliceMut::new(&mut data).write_uint(0, 65).is_err());
+        assert!(BitSlice::new(&data).read_uint(0).is_err());
+        assert!(BitSlice::new(&data).read_uint(65).is_err());
+    }
+
+    #[test]
+    fn fixed_width_be_le_ne_accessors_round_trip() {
+        let mut data = [0u8; 10];
+        let mut w = BitSliceMut::new(&mut data);
+        w.write_u16_be(0x1234).unwrap();
+        w.write_i64_le(-42).unwrap();
+
+        let mut r = BitSlice::new(&data);
+        assert_eq!(r.read_u16_be().unwrap(), 0x1234);
+        assert_eq!(r.read_i64_le().unwrap(), -42);
+
+        let mut data = [0u8; 4];
+        BitSliceMut::new(&mut data).write_u32_ne(0xDEADBEEF).unwrap();
+        assert_eq!(BitSlice::new(&data).read_u32_ne().unwrap(), 0xDEADBEEF);
+    }
+}
@@ -0,0 +1,148 @@
+use alloc::vec::Vec;
+
+use crate::{BitBufMut, BitSlice, Insufficient, Overflow};
+
+/// A growable, heap-backed [`BitBufMut`]: the `alloc`-feature analogue of how
+/// [`crate::BitSliceMut`] relates to a fixed slice. Writes grow the backing
+/// `Vec<u8>` on demand instead of failing with [`Insufficient`], so callers no
+/// longer need to pre-size a buffer or know the exact bit count up front.
+#[derive(Debug, Default)]
+pub struct BitVec {
+    data: Vec<u8>,
+    len: usize,
+}
+
+impl BitVec {
+    pub fn new() -> Self {
+        BitVec {
+            data: Vec::new(),
+            len: 0,
+        }
+    }
+
+    pub fn with_capacity(bits: usize) -> Self {
+        BitVec {
+            data: Vec::with_capacity(bits.div_ceil(8)),
+            len: 0,
+        }
+    }
+
+    /// Number of bits written so far, distinct from `self.into_inner().len() * 8`,
+    /// which is rounded up to a whole number of bytes.
+    pub fn bit_len(&self) -> usize {
+        self.len
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// A read-only view over the bytes written so far, padding included.
+    pub fn as_bit_slice(&self) -> BitSlice<'_> {
+        BitSlice::new(&self.data)
+    }
+}
+
+impl BitBufMut for BitVec {
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn remaining(&self) -> usize {
+        usize::MAX - self.len
+    }
+
+    fn advance(&mut self, bits: usize) -> Result<(), Insufficient> {
+        let new_len = self.len + bits;
+        while self.data.len() * 8 < new_len {
+            self.data.push(0);
+        }
+        self.len = new_len;
+        Ok(())
+    }
+
+    fn write_bool(&mut self, item: bool) -> Result<(), Insufficient> {
+        let byte_idx = self.len / 8;
+        let bit_idx = (self.len & 7) as u8;
+        if byte_idx == self.data.len() {
+            self.data.push(0);
+        }
+        if item {
+            self.data[byte_idx] |= 128 >> bit_idx;
+        } else {
+            self.data[byte_idx] &= 255 ^ (128 >> bit_idx);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    fn write_byte(&mut self, item: u8) -> Result<(), Insufficient> {
+        let byte_idx = self.len / 8;
+        let prefix = (self.len & 7) as u8;
+        if prefix == 0 {
+            if byte_idx == self.data.len() {
+                self.data.push(item);
+            } else {
+                self.data[byte_idx] = item;
+            }
+        } else {
+            let inv_prefix = 8 - prefix;
+            while self.data.len() < byte_idx + 2 {
+                self.data.push(0);
+            }
+            self.data[byte_idx] |= item >> prefix;
+            self.data[byte_idx] &= (item >> prefix) | (255 << inv_prefix);
+            self.data[byte_idx + 1] |= item << inv_prefix;
+            self.data[byte_idx + 1] &= (item << inv_prefix) | (255 << prefix);
+        }
+        self.len += 8;
+        Ok(())
+    }
+
+    fn write(&mut self, data: &[u8], bits: usize) -> Result<usize, Overflow> {
+        let bytes = bits / 8;
+        let len = data.len();
+        if len * 8 < bits {
+            return Err(Overflow);
+        }
+        for &byte in &data[..bytes] {
+            self.write_byte(byte).unwrap();
+        }
+        let rem = bits & 7;
+        if rem != 0 {
+            if len < bytes + 1 {
+                return Err(Overflow);
+            }
+            let value = (data[bytes] >> (8 - rem)) as u64;
+            self.write_uint(value, rem).unwrap();
+        }
+        Ok(bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_byte_grows_the_backing_vec_instead_of_failing() {
+        let mut vec = BitVec::new();
+        vec.write_byte(0xAB).unwrap();
+        vec.write_byte(0xCD).unwrap();
+
+        assert_eq!(vec.bit_len(), 16);
+        assert_eq!(vec.into_inner(), alloc::vec![0xAB, 0xCD]);
+    }
+
+    #[test]
+    fn write_bool_grows_one_byte_at_a_time_across_byte_boundaries() {
+        let mut vec = BitVec::new();
+        for _ in 0..8 {
+            vec.write_bool(true).unwrap();
+        }
+        vec.write_bool(false).unwrap();
+
+        assert_eq!(vec.bit_len(), 9);
+        assert_eq!(vec.into_inner(), alloc::vec![0xFF, 0x00]);
+    }
+}
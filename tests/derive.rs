@@ -0,0 +1,64 @@
+use bitbuf::{BitCodecError, BitRead, BitSlice, BitSliceMut, BitWrite};
+use bitbuf_derive::{BitRead, BitWrite};
+
+#[derive(BitRead, BitWrite, Debug, PartialEq)]
+struct Header {
+    #[bits = 4]
+    version: u8,
+    #[bool]
+    flag: bool,
+    #[bytes = 2]
+    tag: [u8; 2],
+}
+
+#[derive(BitRead, BitWrite, Debug, PartialEq)]
+#[bits = 2]
+enum Frame {
+    Ping,
+    Pong,
+    Data { nested: Header },
+}
+
+#[test]
+fn struct_round_trips_through_a_bit_buffer() {
+    let header = Header {
+        version: 0b1010,
+        flag: true,
+        tag: [0x11, 0x22],
+    };
+
+    let mut buf = [0u8; 4];
+    header.write(&mut BitSliceMut::new(&mut buf)).unwrap();
+
+    let read_back = Header::read(&mut BitSlice::new(&buf)).unwrap();
+    assert_eq!(read_back, header);
+}
+
+#[test]
+fn enum_variants_round_trip_through_a_bit_buffer() {
+    for frame in [
+        Frame::Ping,
+        Frame::Pong,
+        Frame::Data {
+            nested: Header {
+                version: 0b0101,
+                flag: false,
+                tag: [0xAA, 0xBB],
+            },
+        },
+    ] {
+        let mut buf = [0u8; 5];
+        frame.write(&mut BitSliceMut::new(&mut buf)).unwrap();
+
+        let read_back = Frame::read(&mut BitSlice::new(&buf)).unwrap();
+        assert_eq!(read_back, frame);
+    }
+}
+
+#[test]
+fn an_unrecognized_discriminant_is_reported_distinctly_from_running_out_of_bits() {
+    // tag 0b11 has no matching variant, but the bits are all there.
+    let buf = [0b1100_0000u8];
+    let err = Frame::read(&mut BitSlice::new(&buf)).unwrap_err();
+    assert!(matches!(err, BitCodecError::InvalidDiscriminant));
+}